@@ -2,6 +2,7 @@ use futures::{
     channel::{mpsc as async_mpsc, oneshot},
     executor::{block_on, LocalPool},
     prelude::*,
+    select,
     task::LocalSpawnExt,
 };
 use futures_timer::Delay;
@@ -10,12 +11,15 @@ use std::{
     cell::{Cell, RefCell},
     cmp::PartialEq,
     collections::HashMap,
+    future::Future,
     hash::{Hash, Hasher},
+    pin::Pin,
     rc::Rc,
     sync::{
         atomic::{AtomicBool, Ordering},
-        Arc, Mutex,
+        Arc, Mutex, Weak,
     },
+    task::{Context, Poll},
     thread,
     time::Duration,
 };
@@ -23,6 +27,102 @@ use std::{
 use super::{BoardId, Daemon, Matrix};
 use crate::Board;
 
+/// Maximum retries for a fallible `daemon` call before giving up.
+const MAX_DAEMON_RETRIES: u32 = 5;
+
+/// Exponential backoff with a configurable cap, for spacing out retries.
+struct Backoff {
+    base: Duration,
+    factor: u32,
+    max: Duration,
+    current: u32,
+}
+
+impl Backoff {
+    fn new(base: Duration, factor: u32, max: Duration) -> Self {
+        Self {
+            base,
+            factor,
+            max,
+            current: 0,
+        }
+    }
+
+    fn next_delay(&mut self) -> Duration {
+        let delay = self
+            .base
+            .saturating_mul(self.factor.saturating_pow(self.current))
+            .min(self.max);
+        self.current = self.current.saturating_add(1);
+        delay
+    }
+}
+
+impl Default for Backoff {
+    fn default() -> Self {
+        Self::new(Duration::from_millis(50), 2, Duration::from_secs(5))
+    }
+}
+
+/// Errors that mean the board is gone, so retrying is pointless.
+fn is_fatal_daemon_err(err: &str) -> bool {
+    err.contains("disconnected") || err.contains("No such device") || err.contains("not found")
+}
+
+type DaemonJob = Box<dyn FnOnce(&dyn Daemon) + Send>;
+
+/// Runs every call into `daemon` on one dedicated OS thread, since
+/// `Box<dyn Daemon>` isn't guaranteed `Sync`. A call that never returns (a
+/// wedged device) permanently occupies that one thread -- it can't be
+/// canceled, only raced against a timeout by the caller in
+/// `call_with_timeout` -- so every later job queues up behind it and never
+/// runs. Once `Thread` sees `MAX_CONSECUTIVE_TIMEOUTS` timeouts in a row it
+/// calls `mark_dead`, which makes `call` fail fast instead of enqueuing yet
+/// another job that would only ever pile up behind the one that's wedged.
+struct DaemonHandle {
+    jobs: std::sync::mpsc::Sender<DaemonJob>,
+    dead: Arc<AtomicBool>,
+}
+
+impl DaemonHandle {
+    fn new(daemon: Box<dyn Daemon>) -> Self {
+        let (jobs, receiver) = std::sync::mpsc::channel::<DaemonJob>();
+        thread::spawn(move || {
+            for job in receiver {
+                job(daemon.as_ref());
+            }
+        });
+        Self {
+            jobs,
+            dead: Arc::new(AtomicBool::new(false)),
+        }
+    }
+
+    async fn call<T: Send + 'static>(
+        &self,
+        f: impl FnOnce(&dyn Daemon) -> Result<T, String> + Send + 'static,
+    ) -> Result<T, String> {
+        if self.dead.load(Ordering::SeqCst) {
+            return Err("daemon worker thread is wedged".to_string());
+        }
+        let (sender, receiver) = oneshot::channel();
+        self.jobs
+            .send(Box::new(move |daemon| {
+                let _ = sender.send(f(daemon));
+            }))
+            .map_err(|_| "daemon worker thread is gone".to_string())?;
+        receiver
+            .await
+            .unwrap_or_else(|_| Err("daemon worker thread is gone".to_string()))
+    }
+
+    /// Gives up on the daemon worker thread entirely: every later `call`
+    /// fails immediately instead of queuing behind the job that wedged it.
+    fn mark_dead(&self) {
+        self.dead.store(true, Ordering::SeqCst);
+    }
+}
+
 #[derive(Clone, Debug)]
 struct Item<K: Hash + Eq, V> {
     key: K,
@@ -57,10 +157,28 @@ enum SetEnum {
     Mode(Item<(BoardId, u8), (u8, u8)>),
     LedSave(BoardId),
     MatrixGetRate(Item<(), Option<Duration>>),
+    OpTimeout(Item<(), Option<Duration>>),
     Refresh(()),
     Exit(()),
 }
 
+impl SetEnum {
+    /// The board a fatal error from this op should be attributed to, if any.
+    fn board_id(&self) -> Option<BoardId> {
+        match self {
+            SetEnum::KeyMap(Item { key, .. }) => Some(key.0),
+            SetEnum::Color(Item { key, .. }) => Some(key.0),
+            SetEnum::Brightness(Item { key, .. }) => Some(key.0),
+            SetEnum::Mode(Item { key, .. }) => Some(key.0),
+            SetEnum::LedSave(board) => Some(*board),
+            SetEnum::MatrixGetRate(_)
+            | SetEnum::OpTimeout(_)
+            | SetEnum::Refresh(())
+            | SetEnum::Exit(()) => None,
+        }
+    }
+}
+
 #[derive(Debug)]
 struct Set {
     inner: SetEnum,
@@ -74,30 +192,110 @@ impl Set {
     }
 }
 
+/// A request for the currently connected boards and their last known matrix.
+struct Snapshot {
+    reply: oneshot::Sender<Vec<(BoardId, Matrix)>>,
+}
+
+/// A small publish/subscribe bus; subscribers are pruned once dropped.
+struct EventBus<T> {
+    subscribers: Mutex<Vec<Weak<async_mpsc::UnboundedSender<T>>>>,
+}
+
+impl<T> EventBus<T> {
+    fn new() -> Self {
+        Self {
+            subscribers: Mutex::new(Vec::new()),
+        }
+    }
+
+    fn subscribe(&self) -> EventSubscription<T> {
+        let (sender, receiver) = async_mpsc::unbounded();
+        let sender = Arc::new(sender);
+        self.subscribers.lock().unwrap().push(Arc::downgrade(&sender));
+        EventSubscription {
+            sender,
+            receiver,
+        }
+    }
+}
+
+impl<T: Clone> EventBus<T> {
+    fn publish(&self, event: T) {
+        self.subscribers.lock().unwrap().retain(|subscriber| {
+            subscriber
+                .upgrade()
+                .map(|sender| sender.unbounded_send(event.clone()).is_ok())
+                .unwrap_or(false)
+        });
+    }
+}
+
+/// A live subscription to an `EventBus`.
+struct EventSubscription<T> {
+    sender: Arc<async_mpsc::UnboundedSender<T>>,
+    receiver: async_mpsc::UnboundedReceiver<T>,
+}
+
+impl<T> Stream for EventSubscription<T> {
+    type Item = T;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<T>> {
+        // Keep `sender` alive for as long as this subscription exists; it's
+        // never sent to directly, only upgraded-to by `EventBus::publish`.
+        let _ = &self.sender;
+        Pin::new(&mut self.receiver).poll_next(cx)
+    }
+}
+
 #[derive(Clone)]
 pub struct ThreadClient {
     cancels: Arc<Mutex<HashMap<SetEnum, Arc<AtomicBool>>>>,
     channel: async_mpsc::UnboundedSender<Set>,
+    snapshots: async_mpsc::UnboundedSender<Snapshot>,
+    event_bus: Arc<EventBus<ThreadResponse>>,
+    new_boards: Arc<Mutex<Option<async_mpsc::UnboundedReceiver<Board>>>>,
+    worker: Arc<Mutex<Option<thread::JoinHandle<()>>>>,
 }
 
 impl ThreadClient {
-    pub fn new<F: Fn(ThreadResponse) + 'static>(daemon: Box<dyn Daemon>, cb: F) -> Self {
+    pub fn new(daemon: Box<dyn Daemon>) -> Self {
         let (sender, reciever) = async_mpsc::unbounded();
+        let (snapshot_sender, snapshot_reciever) = async_mpsc::unbounded();
+        let (new_boards_tx, new_boards_rx) = async_mpsc::unbounded();
+        let event_bus = Arc::new(EventBus::new());
         let client = Self {
             cancels: Arc::new(Mutex::new(HashMap::new())),
             channel: sender,
+            snapshots: snapshot_sender,
+            event_bus: event_bus.clone(),
+            new_boards: Arc::new(Mutex::new(Some(new_boards_rx))),
+            worker: Arc::new(Mutex::new(None)),
         };
-        let (response_sender, mut response_reciever) = async_mpsc::unbounded();
-        glib::MainContext::default().spawn_local(async move {
-            while let Some(response) = response_reciever.next().await {
-                cb(response)
-            }
-        });
 
-        Thread::new(daemon, client.clone(), response_sender).spawn(reciever);
+        let worker = Thread::new(daemon, client.clone(), event_bus, new_boards_tx)
+            .spawn(reciever, snapshot_reciever);
+        *client.worker.lock().unwrap() = Some(worker);
         client
     }
 
+    /// Subscribes to board lifecycle (by id) and matrix/LED/mode change events.
+    pub fn subscribe(&self) -> impl Stream<Item = ThreadResponse> {
+        self.event_bus.subscribe()
+    }
+
+    /// Returns the currently connected boards and their last known matrix.
+    pub async fn snapshot(&self) -> Vec<(BoardId, Matrix)> {
+        let (reply, receiver) = oneshot::channel();
+        let _ = self.snapshots.unbounded_send(Snapshot { reply });
+        receiver.await.unwrap_or_default()
+    }
+
+    /// Takes the stream of newly connected `Board`s; can only be taken once.
+    pub fn new_boards(&self) -> Option<impl Stream<Item = Board>> {
+        self.new_boards.lock().unwrap().take()
+    }
+
     async fn send(&self, set_enum: SetEnum) -> Result<(), String> {
         let mut cancels = self.cancels.lock().unwrap();
         if let Some(cancel) = cancels.remove(&set_enum) {
@@ -171,18 +369,31 @@ impl ThreadClient {
         self.send(SetEnum::MatrixGetRate(Item::new((), rate))).await
     }
 
+    /// Sets the timeout applied to each individual `daemon` call.
+    pub async fn set_op_timeout(&self, timeout: Option<Duration>) -> Result<(), String> {
+        self.send(SetEnum::OpTimeout(Item::new((), timeout))).await
+    }
+
     pub async fn led_save(&self, board: BoardId) -> Result<(), String> {
         self.send(SetEnum::LedSave(board)).await
     }
 
+    /// Tells the worker thread to stop and blocks until it has terminated.
     pub fn exit(&self) {
         let _ = block_on(self.send(SetEnum::Exit(())));
+        if let Some(worker) = self.worker.lock().unwrap().take() {
+            let _ = worker.join();
+        }
     }
 }
 
+#[derive(Clone)]
 pub enum ThreadResponse {
-    BoardAdded(Board),
+    BoardAdded(BoardId),
     BoardRemoved(BoardId),
+    MatrixChanged(BoardId, Matrix),
+    LedChanged(BoardId, u8, (u8, u8, u8)),
+    ModeChanged(BoardId, u8, (u8, u8)),
 }
 
 struct ThreadBoard {
@@ -199,42 +410,59 @@ impl ThreadBoard {
     }
 }
 
+/// Consecutive `call_with_timeout` timeouts after which the daemon is
+/// declared dead, rather than left to time out silently forever.
+const MAX_CONSECUTIVE_TIMEOUTS: u32 = 3;
+
 struct Thread {
-    daemon: Box<dyn Daemon>,
+    daemon: DaemonHandle,
     boards: RefCell<HashMap<BoardId, ThreadBoard>>,
     client: ThreadClient,
-    response_channel: async_mpsc::UnboundedSender<ThreadResponse>,
+    event_bus: Arc<EventBus<ThreadResponse>>,
+    new_boards: async_mpsc::UnboundedSender<Board>,
     matrix_get_rate: Cell<Option<Duration>>,
+    op_timeout: Cell<Option<Duration>>,
+    consecutive_timeouts: Cell<u32>,
 }
 
 impl Thread {
     fn new(
         daemon: Box<dyn Daemon>,
         client: ThreadClient,
-        response_channel: async_mpsc::UnboundedSender<ThreadResponse>,
+        event_bus: Arc<EventBus<ThreadResponse>>,
+        new_boards: async_mpsc::UnboundedSender<Board>,
     ) -> Self {
         Self {
-            daemon,
+            daemon: DaemonHandle::new(daemon),
             client,
-            response_channel,
+            event_bus,
+            new_boards,
             boards: RefCell::new(HashMap::new()),
             matrix_get_rate: Cell::new(None),
+            op_timeout: Cell::new(None),
+            consecutive_timeouts: Cell::new(0),
         }
     }
 
-    fn spawn(self, mut channel: async_mpsc::UnboundedReceiver<Set>) {
+    /// Spawns the worker thread and returns a handle to it.
+    fn spawn(
+        self,
+        channel: async_mpsc::UnboundedReceiver<Set>,
+        snapshots: async_mpsc::UnboundedReceiver<Snapshot>,
+    ) -> thread::JoinHandle<()> {
         thread::spawn(move || {
             let mut pool = LocalPool::new();
             let spawner = pool.spawner();
 
             let self_ = Rc::new(self);
+            let shutdown = Rc::new(Cell::new(false));
 
-            spawner
-                .spawn_local(clone!(@strong self_ => async move {
-                    loop {
+            let poll_task = spawner
+                .spawn_local_with_handle(clone!(@strong self_, @strong shutdown => async move {
+                    while !shutdown.get() {
                         if let Some(rate) = self_.matrix_get_rate.get() {
                             Delay::new(rate).await;
-                            self_.matrix_refresh_all();
+                            self_.matrix_refresh_all().await;
                         } else {
                             Delay::new(Duration::from_millis(100)).await;
                         }
@@ -243,100 +471,300 @@ impl Thread {
                 .unwrap();
 
             pool.run_until(async move {
-                while let Some(set) = channel.next().await {
-                    if !self_.handle_set(set) {
-                        break;
+                let mut channel = channel.fuse();
+                let mut snapshots = snapshots.fuse();
+                loop {
+                    select! {
+                        set = channel.next() => match set {
+                            Some(set) => {
+                                if !self_.handle_set(set).await {
+                                    break;
+                                }
+                            }
+                            None => break,
+                        },
+                        req = snapshots.next() => match req {
+                            Some(req) => self_.handle_snapshot(req),
+                            None => break,
+                        },
                     }
                 }
+                // Tell the poll loop to stop, then wait for it to notice
+                // (between `Delay`s) and return before we drop the pool.
+                shutdown.set(true);
+                poll_task.await;
             });
-        });
+        })
+    }
+
+    fn handle_snapshot(&self, req: Snapshot) {
+        let snapshot = self
+            .boards
+            .borrow()
+            .iter()
+            .map(|(id, board)| (*id, board.matrix.clone()))
+            .collect();
+        let _ = req.reply.send(snapshot);
+    }
+
+    /// Runs `f`, retrying transient errors with backoff up to
+    /// `MAX_DAEMON_RETRIES` times; a fatal error, or the error after the
+    /// last retry, is returned as is. Bails out early with a canceled error if `cancel` fires while
+    /// waiting between retries, rather than keep retrying a superseded call.
+    async fn call_with_retry<T, Fut>(
+        &self,
+        cancel: &AtomicBool,
+        mut f: impl FnMut() -> Fut,
+    ) -> Result<T, String>
+    where
+        Fut: Future<Output = Result<T, String>>,
+    {
+        let mut backoff = Backoff::default();
+        loop {
+            match f().await {
+                Ok(value) => return Ok(value),
+                Err(err) => {
+                    if is_fatal_daemon_err(&err) || backoff.current >= MAX_DAEMON_RETRIES {
+                        return Err(err);
+                    }
+                    error!("Daemon call failed, retrying: {}", err);
+                    Delay::new(backoff.next_delay()).await;
+                    if cancel.load(Ordering::SeqCst) {
+                        return Err("operation canceled".to_string());
+                    }
+                }
+            }
+        }
+    }
+
+    /// Races `f` against a `Delay`, so a wedged `daemon` call times out.
+    /// Note this only abandons *waiting* for `f`; it can't cancel `f` itself,
+    /// which keeps running on the daemon's one worker thread regardless. If
+    /// that happens `MAX_CONSECUTIVE_TIMEOUTS` times in a row, the daemon is
+    /// declared dead via `mark_daemon_dead` instead of left to time out
+    /// silently forever.
+    async fn call_with_timeout<T: Send + 'static>(
+        &self,
+        timeout: Option<Duration>,
+        f: impl FnOnce(&dyn Daemon) -> Result<T, String> + Send + 'static,
+    ) -> Result<T, String> {
+        let call = self.daemon.call(f).fuse();
+        futures::pin_mut!(call);
+        let result = match timeout {
+            Some(duration) => {
+                let mut delay = Delay::new(duration).fuse();
+                select! {
+                    res = call => res,
+                    _ = delay => Err("operation timed out".to_string()),
+                }
+            }
+            None => call.await,
+        };
+
+        match &result {
+            Ok(_) => self.consecutive_timeouts.set(0),
+            Err(err) if err == "operation timed out" => {
+                let timeouts = self.consecutive_timeouts.get() + 1;
+                self.consecutive_timeouts.set(timeouts);
+                if timeouts >= MAX_CONSECUTIVE_TIMEOUTS {
+                    self.mark_daemon_dead();
+                }
+            }
+            Err(_) => {}
+        }
+
+        result
+    }
+
+    /// Gives up on the daemon entirely: stops it from accepting new jobs and
+    /// drops every known board, publishing `BoardRemoved` for each, so
+    /// callers get a fast explicit error instead of queuing forever behind a
+    /// worker thread that's never coming back.
+    fn mark_daemon_dead(&self) {
+        error!(
+            "Daemon timed out {} times in a row; giving up on it",
+            MAX_CONSECUTIVE_TIMEOUTS
+        );
+        self.daemon.mark_dead();
+        let ids: Vec<BoardId> = self.boards.borrow().keys().copied().collect();
+        for id in ids {
+            self.drop_board(id);
+        }
     }
 
-    fn handle_set(&self, set: Set) -> bool {
+    /// Removes `id` from the known boards and publishes `BoardRemoved`, if
+    /// it was still present.
+    fn drop_board(&self, id: BoardId) {
+        if self.boards.borrow_mut().remove(&id).is_some() {
+            self.event_bus.publish(ThreadResponse::BoardRemoved(id));
+        }
+    }
+
+    async fn handle_set(&self, set: Set) -> bool {
         if set.cancel.load(Ordering::SeqCst) {
             return true;
         }
 
+        let timeout = self.op_timeout.get();
+        let board_id = set.inner.board_id();
         let resp = match set.inner {
             SetEnum::KeyMap(Item { key, value }) => {
-                self.daemon.keymap_set(key.0, key.1, key.2, key.3, value)
+                self.call_with_retry(&set.cancel, || {
+                    self.call_with_timeout(timeout, move |daemon| {
+                        daemon.keymap_set(key.0, key.1, key.2, key.3, value)
+                    })
+                })
+                .await
+            }
+            SetEnum::Color(Item { key, value }) => {
+                let resp = self
+                    .call_with_retry(&set.cancel, || {
+                        self.call_with_timeout(timeout, move |daemon| daemon.set_color(key.0, key.1, value))
+                    })
+                    .await;
+                if resp.is_ok() {
+                    self.event_bus
+                        .publish(ThreadResponse::LedChanged(key.0, key.1, value));
+                }
+                resp
             }
-            SetEnum::Color(Item { key, value }) => self.daemon.set_color(key.0, key.1, value),
             SetEnum::Brightness(Item { key, value }) => {
-                self.daemon.set_brightness(key.0, key.1, value)
+                self.call_with_retry(&set.cancel, || {
+                    self.call_with_timeout(timeout, move |daemon| {
+                        daemon.set_brightness(key.0, key.1, value)
+                    })
+                })
+                .await
             }
             SetEnum::Mode(Item { key, value }) => {
-                self.daemon.set_mode(key.0, key.1, value.0, value.1)
+                let resp = self
+                    .call_with_retry(&set.cancel, || {
+                        self.call_with_timeout(timeout, move |daemon| {
+                            daemon.set_mode(key.0, key.1, value.0, value.1)
+                        })
+                    })
+                    .await;
+                if resp.is_ok() {
+                    self.event_bus
+                        .publish(ThreadResponse::ModeChanged(key.0, key.1, value));
+                }
+                resp
+            }
+            SetEnum::LedSave(board) => {
+                self.call_with_retry(&set.cancel, || {
+                    self.call_with_timeout(timeout, move |daemon| daemon.led_save(board))
+                })
+                .await
             }
-            SetEnum::LedSave(board) => self.daemon.led_save(board),
             SetEnum::MatrixGetRate(Item { value, .. }) => {
                 self.matrix_get_rate.set(value);
                 Ok(())
             }
-            SetEnum::Refresh(()) => self.refresh(),
+            SetEnum::OpTimeout(Item { value, .. }) => {
+                self.op_timeout.set(value);
+                Ok(())
+            }
+            SetEnum::Refresh(()) => self.refresh().await,
             SetEnum::Exit(()) => return false,
         };
 
+        // A fatal error (board disconnected) means this board won't answer
+        // any future op either, so drop it here rather than leaving it in
+        // place to keep silently failing until the next poll notices.
+        if let (Err(err), Some(id)) = (&resp, board_id) {
+            if is_fatal_daemon_err(err) {
+                self.drop_board(id);
+            }
+        }
+
         set.reply(resp);
 
         true
     }
 
-    fn matrix_refresh_all(&self) {
-        for (k, v) in self.boards.borrow_mut().iter_mut() {
-            let matrix = match self.daemon.matrix_get(*k) {
-                Ok(matrix) => matrix,
+    async fn matrix_refresh_all(&self) {
+        let ids: Vec<BoardId> = self.boards.borrow().keys().copied().collect();
+        let mut disconnected = Vec::new();
+        let timeout = self.op_timeout.get();
+        for id in ids {
+            // Refresh isn't tied to a single `Set`, so there's no caller
+            // cancellation to observe here.
+            let no_cancel = AtomicBool::new(false);
+            let result = self
+                .call_with_retry(&no_cancel, || {
+                    self.call_with_timeout(timeout, move |daemon| daemon.matrix_get(id))
+                })
+                .await;
+
+            match result {
+                Ok(matrix) => {
+                    let changed = self
+                        .boards
+                        .borrow_mut()
+                        .get_mut(&id)
+                        .map(|board| {
+                            let changed = board.matrix != matrix;
+                            if changed {
+                                let _ = board.matrix_channel.unbounded_send(matrix.clone());
+                                board.matrix = matrix.clone();
+                            }
+                            changed
+                        })
+                        .unwrap_or(false);
+                    if changed {
+                        self.event_bus
+                            .publish(ThreadResponse::MatrixChanged(id, matrix));
+                    }
+                }
                 Err(err) => {
-                    error!("Failed to get matrix: {}", err);
-                    break;
+                    error!("Failed to get matrix for board, giving up: {}", err);
+                    disconnected.push(id);
                 }
-            };
-            if v.matrix != matrix {
-                let _ = v.matrix_channel.unbounded_send(matrix.clone());
-                v.matrix = matrix;
             }
         }
-    }
 
-    fn refresh(&self) -> Result<(), String> {
-        let mut boards = self.boards.borrow_mut();
+        for id in disconnected {
+            self.drop_board(id);
+        }
+    }
 
-        self.daemon.refresh()?;
+    async fn refresh(&self) -> Result<(), String> {
+        self.daemon.call(|daemon| daemon.refresh()).await?;
 
-        let new_ids = self.daemon.boards()?;
+        let new_ids = self.daemon.call(|daemon| daemon.boards()).await?;
 
         // Removed boards
-        let response_channel = &self.response_channel;
-        boards.retain(|id, _| {
-            if new_ids.iter().find(|i| *i == id).is_none() {
-                // XXX unwrap?
-                response_channel
-                    .unbounded_send(ThreadResponse::BoardRemoved(*id))
-                    .unwrap();
-                return false;
-            }
-            true
-        });
+        {
+            let mut boards = self.boards.borrow_mut();
+            let event_bus = &self.event_bus;
+            boards.retain(|id, _| {
+                if new_ids.iter().find(|i| *i == id).is_none() {
+                    event_bus.publish(ThreadResponse::BoardRemoved(*id));
+                    return false;
+                }
+                true
+            });
+        }
 
         // Added boards
-        for i in &new_ids {
-            if boards.contains_key(i) {
+        for i in new_ids {
+            if self.boards.borrow().contains_key(&i) {
                 continue;
             }
 
             let (matrix_sender, matrix_reciever) = async_mpsc::unbounded();
-            match Board::new(
-                self.daemon.as_ref(),
-                self.client.clone(),
-                *i,
-                matrix_reciever,
-            ) {
+            let client = self.client.clone();
+            // `Board::new` only needs the daemon for a one-time setup read,
+            // so it runs as a job on the daemon thread like any other call.
+            let board = self
+                .daemon
+                .call(move |daemon| Board::new(daemon, client, i, matrix_reciever))
+                .await;
+            match board {
                 Ok(board) => {
-                    // XXX unwrap?
-                    self.response_channel
-                        .unbounded_send(ThreadResponse::BoardAdded(board))
-                        .unwrap();
-                    boards.insert(*i, ThreadBoard::new(matrix_sender));
+                    self.boards.borrow_mut().insert(i, ThreadBoard::new(matrix_sender));
+                    let _ = self.new_boards.unbounded_send(board);
+                    self.event_bus.publish(ThreadResponse::BoardAdded(i));
                 }
                 Err(err) => error!("Failed to add board: {}", err),
             }
@@ -0,0 +1,160 @@
+//! Optional D-Bus control surface for [`ThreadClient`], enabled by the
+//! `dbus` Cargo feature. Lets external scripts, hotkey daemons, or a CLI
+//! drive the keyboard without going through the GTK UI at all: set layer
+//! colors, toggle brightness, switch modes, or query the current matrix.
+//!
+//! Board metadata and the latest `Matrix` are cached and refreshed in the
+//! background (mirroring the `matrix_get_rate` poll in `daemon_thread`) so
+//! property reads are served from the cache instead of hitting the daemon
+//! on every D-Bus call.
+//!
+//! This module self-gates on the `dbus` feature; it still needs `mod dbus;`
+//! added to `daemon/mod.rs` and a `dbus = ["zbus"]` feature plus a `zbus`
+//! dependency added to `Cargo.toml` to actually be built.
+#![cfg(feature = "dbus")]
+
+use futures::{executor::block_on, prelude::*};
+use std::{
+    collections::HashMap,
+    sync::{Arc, Mutex},
+    thread,
+};
+use zbus::{dbus_interface, SignalContext};
+
+use super::{BoardId, Matrix};
+use super::daemon_thread::{ThreadClient, ThreadResponse};
+
+const WELL_KNOWN_NAME: &str = "com.system76.KeyboardConfigurator1";
+const OBJECT_PATH: &str = "/com/system76/KeyboardConfigurator1/Keyboard";
+
+#[derive(Clone, Default)]
+struct BoardCache {
+    matrix: Matrix,
+}
+
+struct KeyboardInterface {
+    client: ThreadClient,
+    boards: Arc<Mutex<HashMap<BoardId, BoardCache>>>,
+}
+
+#[dbus_interface(interface = "com.system76.KeyboardConfigurator1.Keyboard")]
+impl KeyboardInterface {
+    async fn set_color(&self, board: BoardId, index: u8, r: u8, g: u8, b: u8) -> zbus::fdo::Result<()> {
+        self.client
+            .set_color(board, index, (r, g, b))
+            .await
+            .map_err(zbus::fdo::Error::Failed)
+    }
+
+    async fn set_brightness(&self, board: BoardId, index: u8, brightness: i32) -> zbus::fdo::Result<()> {
+        self.client
+            .set_brightness(board, index, brightness)
+            .await
+            .map_err(zbus::fdo::Error::Failed)
+    }
+
+    async fn set_mode(&self, board: BoardId, layer: u8, mode: u8, speed: u8) -> zbus::fdo::Result<()> {
+        self.client
+            .set_mode(board, layer, mode, speed)
+            .await
+            .map_err(zbus::fdo::Error::Failed)
+    }
+
+    async fn keymap_set(
+        &self,
+        board: BoardId,
+        layer: u8,
+        output: u8,
+        input: u8,
+        value: u16,
+    ) -> zbus::fdo::Result<()> {
+        self.client
+            .keymap_set(board, layer, output, input, value)
+            .await
+            .map_err(zbus::fdo::Error::Failed)
+    }
+
+    async fn led_save(&self, board: BoardId) -> zbus::fdo::Result<()> {
+        self.client
+            .led_save(board)
+            .await
+            .map_err(zbus::fdo::Error::Failed)
+    }
+
+    /// Returns the last matrix reported for `board`, served from the
+    /// background-refreshed cache rather than a fresh daemon round-trip.
+    fn matrix(&self, board: BoardId) -> zbus::fdo::Result<Matrix> {
+        self.boards
+            .lock()
+            .unwrap()
+            .get(&board)
+            .map(|cache| cache.matrix.clone())
+            .ok_or_else(|| zbus::fdo::Error::Failed("unknown board".to_string()))
+    }
+
+    #[dbus_interface(signal)]
+    async fn board_added(ctxt: &SignalContext<'_>, board: BoardId) -> zbus::Result<()>;
+
+    #[dbus_interface(signal)]
+    async fn board_removed(ctxt: &SignalContext<'_>, board: BoardId) -> zbus::Result<()>;
+
+    #[dbus_interface(signal)]
+    async fn matrix_changed(ctxt: &SignalContext<'_>, board: BoardId) -> zbus::Result<()>;
+}
+
+/// Starts the D-Bus service on a dedicated thread, proxying calls onto
+/// `client` and mirroring its event bus as D-Bus signals. The returned
+/// `JoinHandle` only resolves if the connection is dropped or serving the
+/// interface fails.
+pub fn serve(client: ThreadClient) -> thread::JoinHandle<zbus::Result<()>> {
+    thread::spawn(move || block_on(serve_async(client)))
+}
+
+async fn serve_async(client: ThreadClient) -> zbus::Result<()> {
+    let boards = Arc::new(Mutex::new(HashMap::new()));
+    let iface = KeyboardInterface {
+        client: client.clone(),
+        boards: boards.clone(),
+    };
+
+    let connection = zbus::ConnectionBuilder::session()?
+        .name(WELL_KNOWN_NAME)?
+        .serve_at(OBJECT_PATH, iface)?
+        .build()
+        .await?;
+
+    let object_server = connection.object_server();
+    let iface_ref = object_server
+        .interface::<_, KeyboardInterface>(OBJECT_PATH)
+        .await?;
+
+    // Subscribe before backfilling so a board that connects in between is
+    // covered by both: the snapshot picks it up, and its (now redundant,
+    // but harmless) BoardAdded event just re-inserts the same cache entry.
+    let mut events = client.subscribe();
+    for (id, matrix) in client.snapshot().await {
+        boards.lock().unwrap().insert(id, BoardCache { matrix });
+    }
+
+    while let Some(event) = events.next().await {
+        match event {
+            ThreadResponse::BoardAdded(id) => {
+                boards.lock().unwrap().insert(id, BoardCache::default());
+                KeyboardInterface::board_added(iface_ref.signal_context(), id).await?;
+            }
+            ThreadResponse::BoardRemoved(id) => {
+                boards.lock().unwrap().remove(&id);
+                KeyboardInterface::board_removed(iface_ref.signal_context(), id).await?;
+            }
+            ThreadResponse::MatrixChanged(id, matrix) => {
+                if let Some(cache) = boards.lock().unwrap().get_mut(&id) {
+                    cache.matrix = matrix;
+                }
+                KeyboardInterface::matrix_changed(iface_ref.signal_context(), id).await?;
+            }
+            ThreadResponse::LedChanged(..) | ThreadResponse::ModeChanged(..) => {}
+        }
+    }
+
+    Ok(())
+}
@@ -0,0 +1,138 @@
+//! Loom model of the `cancels` dedup/cancellation race in
+//! `ThreadClient::send` (see `daemon_thread.rs`): concurrent `send` calls
+//! for the same key each cancel whatever was previously registered, then
+//! hand their `Set` to a worker that skips it if it's been canceled by the
+//! time it's dequeued. This models exactly that dance, minus the async
+//! plumbing, so loom can exhaustively (or budget-bounded) explore the
+//! interleavings.
+//!
+//! Run with:
+//!
+//!     RUSTFLAGS="--cfg loom" cargo test --test loom_cancels --release
+//!
+//! `LOOM_MAX_PREEMPTIONS` bounds how many thread preemptions loom explores
+//! per schedule; raise it to search more interleavings at the cost of
+//! runtime, lower it for a quick smoke check in CI.
+//!
+//! This test also needs a `loom` dev-dependency added to Cargo.toml; that
+//! file doesn't exist in this tree to edit.
+#![cfg(loom)]
+
+use loom::sync::atomic::{AtomicBool, Ordering};
+use loom::sync::{Arc, Mutex};
+use loom::thread;
+use std::collections::{HashMap, VecDeque};
+use std::env;
+
+type Key = &'static str;
+
+/// A model of the shared state behind `ThreadClient::send` /
+/// `Thread::handle_set`: one cancel-flag slot per key (with the value it was
+/// registered for, as the real map's `SetEnum` key carries its value), plus
+/// a FIFO queue standing in for the real `async_mpsc` channel between them.
+struct Model {
+    cancels: Mutex<HashMap<Key, (u32, Arc<AtomicBool>)>>,
+    queue: Mutex<VecDeque<(u32, Arc<AtomicBool>)>>,
+}
+
+impl Model {
+    fn new() -> Self {
+        Self {
+            cancels: Mutex::new(HashMap::new()),
+            queue: Mutex::new(VecDeque::new()),
+        }
+    }
+
+    /// Mirrors `ThreadClient::send`: cancel whatever was queued before for
+    /// `key`, register a fresh cancel flag, then enqueue the new value.
+    fn send(&self, key: Key, value: u32) {
+        let mut cancels = self.cancels.lock().unwrap();
+        if let Some((_, previous)) = cancels.remove(key) {
+            previous.store(true, Ordering::SeqCst);
+        }
+        let cancel = Arc::new(AtomicBool::new(false));
+        cancels.insert(key, (value, cancel.clone()));
+        drop(cancels);
+
+        self.queue.lock().unwrap().push_back((value, cancel));
+    }
+
+    /// Mirrors `Thread::handle_set` draining the queue: skip an entry if
+    /// it's been canceled by the time it's dequeued, else apply it.
+    fn drain(&self) -> Vec<u32> {
+        let mut applied = Vec::new();
+        let mut queue = self.queue.lock().unwrap();
+        while let Some((value, cancel)) = queue.pop_front() {
+            if !cancel.load(Ordering::SeqCst) {
+                applied.push(value);
+            }
+        }
+        applied
+    }
+}
+
+fn max_preemptions() -> usize {
+    env::var("LOOM_MAX_PREEMPTIONS")
+        .ok()
+        .and_then(|v| v.parse().ok())
+        .unwrap_or(3)
+}
+
+#[test]
+fn drain_racing_concurrent_sends_never_applies_out_of_order() {
+    let mut builder = loom::model::Builder::new();
+    builder.preemption_bound = Some(max_preemptions());
+
+    builder.check(|| {
+        let model = Arc::new(Model::new());
+
+        let senders: Vec<_> = (1..=3u32)
+            .map(|value| {
+                let model = model.clone();
+                thread::spawn(move || model.send("color", value))
+            })
+            .collect();
+
+        // Race a `drain` concurrently with the sends above instead of
+        // waiting for them to finish first: this is the real race the
+        // worker thread hits in production, popping and checking a Set's
+        // cancel flag while more `send`s can still be landing.
+        let racing_drain = {
+            let model = model.clone();
+            thread::spawn(move || model.drain())
+        };
+
+        let mut applied = racing_drain.join().unwrap();
+        for sender in senders {
+            sender.join().unwrap();
+        }
+        // Mop up whatever the racing drain ran too early to see.
+        applied.extend(model.drain());
+
+        // The three sender threads race each other with no ordering
+        // guarantee between them, so `value` (1, 2, 3) doesn't track real
+        // push order and applied entries aren't expected to come out
+        // non-decreasing -- e.g. `[2, 1]` is a perfectly legitimate
+        // interleaving. What the dedup/cancel logic does promise is that it
+        // never applies more values than were ever sent, and that the value
+        // whose `send` registered last (the one still in `cancels`) is the
+        // last one applied, so a stale value never lands after it.
+        assert!(
+            applied.len() <= 3,
+            "drain applied more values than were ever sent: {:?}",
+            applied
+        );
+        let last_registered = model
+            .cancels
+            .lock()
+            .unwrap()
+            .get("color")
+            .map(|(value, _)| *value);
+        assert_eq!(
+            applied.last().copied(),
+            last_registered,
+            "a stale value was applied after the last-registered one: {:?}",
+            applied
+        );
+    });
+}